@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+
+use crate::app::models::{
+    AlbumDescription, EpisodeDescription, PlaylistDescription, ShowDescription, SongDescription,
+};
+
+#[derive(Clone, Debug)]
+pub struct SpotifyApiError(pub String);
+
+#[async_trait(?Send)]
+pub trait SpotifyApiClient {
+    async fn get_album(&self, id: &str) -> Result<AlbumDescription, SpotifyApiError>;
+    async fn get_playlist(&self, id: &str) -> Result<PlaylistDescription, SpotifyApiError>;
+    async fn get_playlist_tracks(
+        &self,
+        id: &str,
+        offset: u32,
+        batch_size: u32,
+    ) -> Result<Vec<SongDescription>, SpotifyApiError>;
+    async fn save_album(&self, id: &str) -> Result<AlbumDescription, SpotifyApiError>;
+    async fn remove_saved_album(&self, id: &str) -> Result<(), SpotifyApiError>;
+    async fn get_recommendations(
+        &self,
+        seed_tracks: Vec<String>,
+        seed_artists: Vec<String>,
+    ) -> Result<Vec<SongDescription>, SpotifyApiError>;
+    async fn get_show(&self, id: &str) -> Result<ShowDescription, SpotifyApiError>;
+    async fn get_show_episodes(
+        &self,
+        id: &str,
+        offset: u32,
+        batch_size: u32,
+    ) -> Result<Vec<EpisodeDescription>, SpotifyApiError>;
+}
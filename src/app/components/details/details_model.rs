@@ -4,13 +4,18 @@ use std::cell::Ref;
 use std::ops::Deref;
 use std::rc::Rc;
 
+use crate::app::components::radio::{
+    dispatch_start_radio, make_start_radio_action, radio_seed_artists, radio_seed_tracks,
+};
+use crate::app::components::song_query::{query_tokens, SongMatcher};
 use crate::app::components::{
     handle_error, labels, PlaylistModel, SelectionTool, SelectionToolsModel,
 };
 use crate::app::dispatch::ActionDispatcher;
 use crate::app::models::*;
 use crate::app::state::{
-    BrowserAction, BrowserEvent, PlaybackAction, PlaylistSource, SelectionAction, SelectionState,
+    BrowserAction, BrowserEvent, FilterAction, PlaybackAction, PlaylistSource, SelectionAction,
+    SelectionState,
 };
 use crate::app::{AppAction, AppEvent, AppModel, AppState, ListDiff};
 
@@ -39,6 +44,16 @@ impl DetailsModel {
             .map_state_opt(|s| s.browser.details_state(&self.id)?.content.as_ref())
     }
 
+    fn filter_query(&self) -> Option<impl Deref<Target = String> + '_> {
+        self.app_model
+            .map_state_opt(|s| Some(&s.browser.details_state(&self.id)?.filter))
+    }
+
+    pub fn update_filter(&self, query: String) {
+        self.dispatcher
+            .dispatch(FilterAction::UpdateQuery(self.id.clone(), query).into());
+    }
+
     pub fn load_album_info(&self) {
         let id = self.id.clone();
         let api = self.app_model.get_spotify();
@@ -80,12 +95,46 @@ impl DetailsModel {
             }));
         }
     }
+
+    pub fn start_radio(&self) {
+        if let Some(songs) = self.songs_ref() {
+            dispatch_start_radio(
+                &self.app_model,
+                self.dispatcher.box_clone(),
+                radio_seed_tracks(&songs, None),
+                radio_seed_artists(&songs),
+                self.id.clone(),
+            );
+        }
+    }
 }
 
 impl DetailsModel {
     fn state(&self) -> Ref<'_, AppState> {
         self.app_model.get_state()
     }
+
+    fn sort_by<K: Ord>(&self, key_fn: impl Fn(&SongDescription) -> K) {
+        let sorted = match self.songs_ref() {
+            Some(songs) => {
+                let mut sorted: Vec<SongDescription> = songs.clone();
+                sorted.sort_by_key(|s| key_fn(s));
+                sorted
+            }
+            None => return,
+        };
+        let ordered_ids: Vec<String> = sorted.iter().map(|s| s.id.clone()).collect();
+        let source = Some(PlaylistSource::Album(self.id.clone()));
+        let is_playing = self.state().playback.source() == source.as_ref();
+
+        self.dispatcher
+            .dispatch(BrowserAction::ReorderSongs(self.id.clone(), ordered_ids).into());
+
+        if is_playing {
+            self.dispatcher
+                .dispatch(PlaybackAction::LoadPlaylist(source, sorted).into());
+        }
+    }
 }
 
 impl PlaylistModel for DetailsModel {
@@ -132,20 +181,41 @@ impl PlaylistModel for DetailsModel {
     }
 
     fn diff_for_event(&self, event: &AppEvent) -> Option<ListDiff<SongModel>> {
-        if matches!(
-            event,
-            AppEvent::BrowserEvent(BrowserEvent::AlbumDetailsLoaded(id)) if id == &self.id
-        ) {
-            let songs = self.songs_ref()?;
-            Some(ListDiff::Set(
-                songs
-                    .iter()
-                    .enumerate()
-                    .map(|(i, s)| s.to_song_model(i))
-                    .collect(),
-            ))
-        } else {
-            None
+        match event {
+            AppEvent::BrowserEvent(BrowserEvent::AlbumDetailsLoaded(id)) if id == &self.id => {
+                let songs = self.songs_ref()?;
+                Some(ListDiff::Set(
+                    songs
+                        .iter()
+                        .enumerate()
+                        .map(|(i, s)| s.to_song_model(i))
+                        .collect(),
+                ))
+            }
+            AppEvent::BrowserEvent(BrowserEvent::SongsReordered(id)) if id == &self.id => {
+                let songs = self.songs_ref()?;
+                Some(ListDiff::Set(
+                    songs
+                        .iter()
+                        .enumerate()
+                        .map(|(i, s)| s.to_song_model(i))
+                        .collect(),
+                ))
+            }
+            AppEvent::BrowserEvent(BrowserEvent::FilterChanged(id)) if id == &self.id => {
+                let songs = self.songs_ref()?;
+                let query = self.filter_query()?;
+                let matcher = SongMatcher::new(&query_tokens(&query));
+                Some(ListDiff::Set(
+                    songs
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, s)| matcher.matches(s))
+                        .map(|(i, s)| s.to_song_model(i))
+                        .collect(),
+                ))
+            }
+            _ => None,
         }
     }
 
@@ -160,6 +230,12 @@ impl PlaylistModel for DetailsModel {
         }
         group.add_action(&song.make_link_action(None));
         group.add_action(&song.make_queue_action(self.dispatcher.box_clone(), None));
+        group.add_action(&make_start_radio_action(
+            &self.app_model,
+            self.dispatcher.box_clone(),
+            &songs,
+            id,
+        ));
 
         Some(group.upcast())
     }
@@ -178,6 +254,7 @@ impl PlaylistModel for DetailsModel {
 
         menu.append(Some(&*labels::COPY_LINK), Some("song.copy_link"));
         menu.append(Some(&*labels::ADD_TO_QUEUE), Some("song.queue"));
+        menu.append(Some(&*labels::START_RADIO), Some("song.start_radio"));
         Some(menu.upcast())
     }
 }
@@ -201,6 +278,23 @@ impl SelectionToolsModel for DetailsModel {
                 }
                 .into()
             }),
+            (SelectionTool::SortByTitle, None) => {
+                self.sort_by(|s| s.title.to_lowercase());
+                None
+            }
+            (SelectionTool::SortByArtist, None) => {
+                self.sort_by(|s| {
+                    s.artists
+                        .first()
+                        .map(|a| a.name.to_lowercase())
+                        .unwrap_or_default()
+                });
+                None
+            }
+            (SelectionTool::SortByDuration, None) => {
+                self.sort_by(|s| s.duration);
+                None
+            }
             _ => None,
         };
         if let Some(action) = action {
@@ -0,0 +1,3 @@
+mod details_model;
+
+pub use details_model::DetailsModel;
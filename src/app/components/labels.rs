@@ -0,0 +1,6 @@
+pub static VIEW_ALBUM: &str = "View album";
+pub static MORE_FROM: &str = "More from";
+pub static COPY_LINK: &str = "Copy link";
+pub static ADD_TO_QUEUE: &str = "Add to queue";
+pub static START_RADIO: &str = "Start radio from here";
+pub static GO_TO_SHOW: &str = "Go to show";
@@ -0,0 +1,39 @@
+mod details;
+pub mod labels;
+mod playlist_details;
+pub mod radio;
+mod show_details;
+pub mod song_query;
+
+use std::ops::Deref;
+
+use crate::app::backend::SpotifyApiError;
+use crate::app::models::SongModel;
+use crate::app::state::{AppAction, SelectionState, SelectionTool};
+use crate::app::{AppEvent, ListDiff};
+
+pub use details::DetailsModel;
+pub use playlist_details::PlaylistDetailsModel;
+pub use show_details::ShowDetailsModel;
+
+pub fn handle_error(err: SpotifyApiError) -> Option<AppAction> {
+    eprintln!("spotify api error: {}", err.0);
+    None
+}
+
+pub trait PlaylistModel {
+    fn current_song_id(&self) -> Option<String>;
+    fn play_song(&self, id: &str);
+    fn diff_for_event(&self, event: &AppEvent) -> Option<ListDiff<SongModel>>;
+    fn actions_for(&self, id: &str) -> Option<gio::ActionGroup>;
+    fn menu_for(&self, id: &str) -> Option<gio::MenuModel>;
+    fn select_song(&self, id: &str);
+    fn deselect_song(&self, id: &str);
+    fn enable_selection(&self) -> bool;
+    fn selection(&self) -> Option<Box<dyn Deref<Target = SelectionState> + '_>>;
+}
+
+pub trait SelectionToolsModel {
+    fn selection(&self) -> Option<Box<dyn Deref<Target = SelectionState> + '_>>;
+    fn handle_tool_activated(&self, selection: &SelectionState, tool: &SelectionTool);
+}
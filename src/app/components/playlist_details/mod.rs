@@ -0,0 +1,3 @@
+mod playlist_details_model;
+
+pub use playlist_details_model::PlaylistDetailsModel;
@@ -1,15 +1,23 @@
+use futures::channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
+use futures::StreamExt;
 use gio::prelude::*;
 use gio::{ActionMapExt, SimpleActionGroup};
+use glib::MainContext;
 use std::cell::Ref;
 use std::ops::Deref;
 use std::rc::Rc;
 
+use crate::app::components::radio::{
+    dispatch_start_radio, make_start_radio_action, radio_seed_artists, radio_seed_tracks,
+};
+use crate::app::components::song_query::{query_tokens, SongMatcher};
 use crate::app::components::{
     handle_error, labels, PlaylistModel, SelectionTool, SelectionToolsModel,
 };
 use crate::app::models::*;
 use crate::app::state::{
-    BrowserAction, BrowserEvent, PlaybackAction, PlaylistSource, SelectionAction, SelectionState,
+    BrowserAction, BrowserEvent, FilterAction, PlaybackAction, PlaylistSource, SelectionAction,
+    SelectionState,
 };
 use crate::app::{ActionDispatcher, AppAction, AppEvent, AppModel, AppState, ListDiff};
 
@@ -17,14 +25,24 @@ pub struct PlaylistDetailsModel {
     pub id: String,
     app_model: Rc<AppModel>,
     dispatcher: Box<dyn ActionDispatcher>,
+    prefetch_requests: UnboundedSender<usize>,
 }
 
 impl PlaylistDetailsModel {
     pub fn new(id: String, app_model: Rc<AppModel>, dispatcher: Box<dyn ActionDispatcher>) -> Self {
+        let (prefetch_requests, requests) = unbounded();
+        spawn_prefetch_worker(
+            id.clone(),
+            Rc::clone(&app_model),
+            dispatcher.box_clone(),
+            requests,
+        );
+
         Self {
             id,
             app_model,
             dispatcher,
+            prefetch_requests,
         }
     }
 
@@ -49,6 +67,16 @@ impl PlaylistDetailsModel {
         })
     }
 
+    fn filter_query(&self) -> Option<impl Deref<Target = String> + '_> {
+        self.app_model
+            .map_state_opt(|s| Some(&s.browser.playlist_details_state(&self.id)?.filter))
+    }
+
+    pub fn update_filter(&self, query: String) {
+        self.dispatcher
+            .dispatch(FilterAction::UpdateQuery(self.id.clone(), query).into());
+    }
+
     pub fn load_playlist_info(&self) {
         let api = self.app_model.get_spotify();
         let id = self.id.clone();
@@ -60,23 +88,10 @@ impl PlaylistDetailsModel {
         }));
     }
 
-    pub fn load_more_tracks(&self) -> Option<()> {
-        let api = self.app_model.get_spotify();
-        let id = self.id.clone();
-
-        let state = self.app_model.get_state();
-        let page = &state.browser.playlist_details_state(&id)?.next_page;
-        let next_offset = page.next_offset? as u32;
-        let batch_size = page.batch_size as u32;
-
-        self.dispatcher.dispatch_async(Box::pin(async move {
-            match api.get_playlist_tracks(&id, next_offset, batch_size).await {
-                Ok(tracks) => Some(BrowserAction::AppendPlaylistTracks(id, tracks).into()),
-                Err(err) => handle_error(err),
-            }
-        }));
-
-        Some(())
+    /// Called by the track list as rows scroll into view so the next page
+    /// can be fetched before the user reaches the end of what's loaded.
+    pub fn request_prefetch(&self, visible_row: usize) {
+        let _ = self.prefetch_requests.unbounded_send(visible_row);
     }
 
     pub fn view_owner(&self) {
@@ -86,12 +101,46 @@ impl PlaylistDetailsModel {
                 .dispatch(AppAction::ViewUser(owner.to_owned()));
         }
     }
+
+    pub fn start_radio(&self) {
+        if let Some(songs) = self.songs_ref() {
+            dispatch_start_radio(
+                &self.app_model,
+                self.dispatcher.box_clone(),
+                radio_seed_tracks(&songs, None),
+                radio_seed_artists(&songs),
+                self.id.clone(),
+            );
+        }
+    }
 }
 
 impl PlaylistDetailsModel {
     fn state(&self) -> Ref<'_, AppState> {
         self.app_model.get_state()
     }
+
+    fn sort_by<K: Ord>(&self, key_fn: impl Fn(&SongDescription) -> K) {
+        let sorted = match self.songs_ref() {
+            Some(songs) => {
+                let mut sorted: Vec<SongDescription> = songs.clone();
+                sorted.sort_by_key(|s| key_fn(s));
+                sorted
+            }
+            None => return,
+        };
+        let ordered_ids: Vec<String> = sorted.iter().map(|s| s.id.clone()).collect();
+        let source = Some(PlaylistSource::Playlist(self.id.clone()));
+        let is_playing = self.state().playback.source() == source.as_ref();
+
+        self.dispatcher
+            .dispatch(BrowserAction::ReorderSongs(self.id.clone(), ordered_ids).into());
+
+        if is_playing {
+            self.dispatcher
+                .dispatch(PlaybackAction::LoadPlaylist(source, sorted).into());
+        }
+    }
 }
 
 impl PlaylistModel for PlaylistDetailsModel {
@@ -137,6 +186,29 @@ impl PlaylistModel for PlaylistDetailsModel {
                         .collect(),
                 ))
             }
+            AppEvent::BrowserEvent(BrowserEvent::SongsReordered(id)) if id == &self.id => {
+                let songs = self.songs_ref()?;
+                Some(ListDiff::Set(
+                    songs
+                        .iter()
+                        .enumerate()
+                        .map(|(i, s)| s.to_song_model(i))
+                        .collect(),
+                ))
+            }
+            AppEvent::BrowserEvent(BrowserEvent::FilterChanged(id)) if id == &self.id => {
+                let songs = self.songs_ref()?;
+                let query = self.filter_query()?;
+                let matcher = SongMatcher::new(&query_tokens(&query));
+                Some(ListDiff::Set(
+                    songs
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, s)| matcher.matches(s))
+                        .map(|(i, s)| s.to_song_model(i))
+                        .collect(),
+                ))
+            }
             _ => None,
         }
     }
@@ -153,6 +225,12 @@ impl PlaylistModel for PlaylistDetailsModel {
         group.add_action(&song.make_album_action(self.dispatcher.box_clone(), None));
         group.add_action(&song.make_link_action(None));
         group.add_action(&song.make_queue_action(self.dispatcher.box_clone(), None));
+        group.add_action(&make_start_radio_action(
+            &self.app_model,
+            self.dispatcher.box_clone(),
+            &songs,
+            id,
+        ));
 
         Some(group.upcast())
     }
@@ -172,6 +250,7 @@ impl PlaylistModel for PlaylistDetailsModel {
 
         menu.append(Some(&*labels::COPY_LINK), Some("song.copy_link"));
         menu.append(Some(&*labels::ADD_TO_QUEUE), Some("song.queue"));
+        menu.append(Some(&*labels::START_RADIO), Some("song.start_radio"));
 
         Some(menu.upcast())
     }
@@ -221,6 +300,27 @@ impl SelectionToolsModel for PlaylistDetailsModel {
                 }
                 .into()
             }),
+            (SelectionTool::SortByTitle, None) => {
+                self.sort_by(|s| s.title.to_lowercase());
+                None
+            }
+            (SelectionTool::SortByArtist, None) => {
+                self.sort_by(|s| {
+                    s.artists
+                        .first()
+                        .map(|a| a.name.to_lowercase())
+                        .unwrap_or_default()
+                });
+                None
+            }
+            (SelectionTool::SortByDuration, None) => {
+                self.sort_by(|s| s.duration);
+                None
+            }
+            (SelectionTool::SortByAddedDate, None) => {
+                self.sort_by(|s| s.added_at.clone().unwrap_or_default());
+                None
+            }
             _ => None,
         };
         if let Some(action) = action {
@@ -228,3 +328,78 @@ impl SelectionToolsModel for PlaylistDetailsModel {
         }
     }
 }
+
+const PREFETCH_ROW_THRESHOLD: usize = 20;
+
+fn needs_prefetch(loaded: usize, visible_row: usize, threshold: usize) -> bool {
+    loaded.saturating_sub(visible_row) <= threshold
+}
+
+fn spawn_prefetch_worker(
+    id: String,
+    app_model: Rc<AppModel>,
+    dispatcher: Box<dyn ActionDispatcher>,
+    mut requests: UnboundedReceiver<usize>,
+) {
+    let api = app_model.get_spotify();
+
+    MainContext::default().spawn_local(async move {
+        while let Some(visible_row) = requests.next().await {
+            let next_page = {
+                let state = app_model.get_state();
+                match state.browser.playlist_details_state(&id) {
+                    Some(details) => details.next_page.clone(),
+                    None => continue,
+                }
+            };
+
+            let next_offset = match next_page.next_offset {
+                Some(offset) => offset as u32,
+                None => continue,
+            };
+
+            let loaded = app_model
+                .get_state()
+                .browser
+                .playlist_details_state(&id)
+                .and_then(|details| details.playlist.as_ref())
+                .map(|playlist| playlist.songs.len())
+                .unwrap_or(0);
+
+            if !needs_prefetch(loaded, visible_row, PREFETCH_ROW_THRESHOLD) {
+                continue;
+            }
+
+            match api
+                .get_playlist_tracks(&id, next_offset, next_page.batch_size as u32)
+                .await
+            {
+                Ok(tracks) => dispatcher
+                    .dispatch(BrowserAction::AppendPlaylistTracks(id.clone(), tracks).into()),
+                Err(err) => {
+                    handle_error(err);
+                }
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn does_not_prefetch_while_far_from_the_end_of_loaded_rows() {
+        assert!(!needs_prefetch(100, 10, PREFETCH_ROW_THRESHOLD));
+    }
+
+    #[test]
+    fn prefetches_once_within_the_threshold_of_the_end() {
+        assert!(needs_prefetch(100, 85, PREFETCH_ROW_THRESHOLD));
+    }
+
+    #[test]
+    fn prefetches_when_the_visible_row_is_past_what_is_loaded() {
+        assert!(needs_prefetch(10, 50, PREFETCH_ROW_THRESHOLD));
+    }
+}
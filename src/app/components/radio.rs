@@ -0,0 +1,127 @@
+use std::rc::Rc;
+
+use crate::app::components::handle_error;
+use crate::app::models::SongDescription;
+use crate::app::state::{PlaybackAction, PlaylistSource};
+use crate::app::{ActionDispatcher, AppModel};
+
+const RADIO_SEED_TRACKS: usize = 5;
+
+pub fn radio_seed_tracks(songs: &[SongDescription], lead: Option<&str>) -> Vec<String> {
+    let mut seeds: Vec<String> = lead.map(|id| id.to_string()).into_iter().collect();
+    seeds.extend(
+        songs
+            .iter()
+            .map(|s| s.id.clone())
+            .filter(|id| Some(id.as_str()) != lead),
+    );
+    seeds.truncate(RADIO_SEED_TRACKS);
+    seeds
+}
+
+pub fn radio_seed_artists(songs: &[SongDescription]) -> Vec<String> {
+    let mut seeds: Vec<String> = Vec::new();
+    for song in songs {
+        if let Some(artist) = song.artists.first() {
+            if !seeds.contains(&artist.id) {
+                seeds.push(artist.id.clone());
+            }
+        }
+    }
+    seeds.truncate(RADIO_SEED_TRACKS);
+    seeds
+}
+
+pub fn dispatch_start_radio(
+    app_model: &Rc<AppModel>,
+    dispatcher: Box<dyn ActionDispatcher>,
+    seed_tracks: Vec<String>,
+    seed_artists: Vec<String>,
+    seed_id: String,
+) {
+    let api = app_model.get_spotify();
+    let callback_dispatcher = dispatcher.box_clone();
+
+    dispatcher.dispatch_async(Box::pin(async move {
+        match api.get_recommendations(seed_tracks, seed_artists).await {
+            Ok(tracks) => {
+                let first_id = tracks.first().map(|s| s.id.clone());
+                callback_dispatcher.dispatch(
+                    PlaybackAction::LoadPlaylist(Some(PlaylistSource::Radio(seed_id)), tracks)
+                        .into(),
+                );
+                first_id.map(|id| PlaybackAction::Load(id).into())
+            }
+            Err(err) => handle_error(err),
+        }
+    }));
+}
+
+pub fn make_start_radio_action(
+    app_model: &Rc<AppModel>,
+    dispatcher: Box<dyn ActionDispatcher>,
+    songs: &[SongDescription],
+    seed_id: &str,
+) -> gio::SimpleAction {
+    let action = gio::SimpleAction::new("start_radio", None);
+
+    let app_model = Rc::clone(app_model);
+    let seed_tracks = radio_seed_tracks(songs, Some(seed_id));
+    let seed_artists = radio_seed_artists(songs);
+    let seed_id = seed_id.to_string();
+
+    action.connect_activate(move |_, _| {
+        dispatch_start_radio(
+            &app_model,
+            dispatcher.box_clone(),
+            seed_tracks.clone(),
+            seed_artists.clone(),
+            seed_id.clone(),
+        );
+    });
+
+    action
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::models::ArtistRef;
+
+    fn song(id: &str, artist_id: &str) -> SongDescription {
+        SongDescription {
+            id: id.to_string(),
+            title: id.to_string(),
+            artists: vec![ArtistRef {
+                id: artist_id.to_string(),
+                name: artist_id.to_string(),
+            }],
+            duration: 0,
+            added_at: None,
+        }
+    }
+
+    #[test]
+    fn seed_tracks_puts_the_lead_first_and_caps_at_five() {
+        let songs: Vec<SongDescription> = (0..10).map(|i| song(&i.to_string(), "a")).collect();
+        let seeds = radio_seed_tracks(&songs, Some("3"));
+        assert_eq!(seeds.len(), 5);
+        assert_eq!(seeds[0], "3");
+        assert!(!seeds[1..].contains(&"3".to_string()));
+    }
+
+    #[test]
+    fn seed_artists_are_deduplicated_and_capped() {
+        let songs = vec![
+            song("1", "a"),
+            song("2", "a"),
+            song("3", "b"),
+            song("4", "c"),
+            song("5", "d"),
+            song("6", "e"),
+            song("7", "f"),
+        ];
+        let seeds = radio_seed_artists(&songs);
+        assert_eq!(seeds, vec!["a", "b", "c", "d", "e"]);
+    }
+}
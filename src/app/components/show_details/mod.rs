@@ -0,0 +1,2 @@
+mod show_details_model;
+pub use show_details_model::ShowDetailsModel;
@@ -0,0 +1,220 @@
+use gio::prelude::*;
+use gio::{ActionMapExt, SimpleActionGroup};
+use std::cell::Ref;
+use std::ops::Deref;
+use std::rc::Rc;
+
+use crate::app::components::{
+    handle_error, labels, PlaylistModel, SelectionTool, SelectionToolsModel,
+};
+use crate::app::models::*;
+use crate::app::state::{
+    BrowserAction, BrowserEvent, PlaybackAction, PlaylistSource, SelectionAction, SelectionState,
+};
+use crate::app::{ActionDispatcher, AppAction, AppEvent, AppModel, AppState, ListDiff};
+
+pub struct ShowDetailsModel {
+    pub id: String,
+    app_model: Rc<AppModel>,
+    dispatcher: Box<dyn ActionDispatcher>,
+}
+
+impl ShowDetailsModel {
+    pub fn new(id: String, app_model: Rc<AppModel>, dispatcher: Box<dyn ActionDispatcher>) -> Self {
+        Self {
+            id,
+            app_model,
+            dispatcher,
+        }
+    }
+
+    fn episodes_ref(&self) -> Option<impl Deref<Target = Vec<EpisodeDescription>> + '_> {
+        self.app_model.map_state_opt(|s| {
+            Some(&s.browser.show_details_state(&self.id)?.show.as_ref()?.episodes)
+        })
+    }
+
+    pub fn get_show_info(&self) -> Option<impl Deref<Target = ShowDescription> + '_> {
+        self.app_model
+            .map_state_opt(|s| s.browser.show_details_state(&self.id)?.show.as_ref())
+    }
+
+    pub fn load_show_info(&self) {
+        let id = self.id.clone();
+        let api = self.app_model.get_spotify();
+        self.dispatcher.dispatch_async(Box::pin(async move {
+            match api.get_show(&id).await {
+                Ok(show) => Some(BrowserAction::SetShowDetails(show).into()),
+                Err(err) => handle_error(err),
+            }
+        }));
+    }
+
+    pub fn load_more_episodes(&self) -> Option<()> {
+        let api = self.app_model.get_spotify();
+        let id = self.id.clone();
+
+        let state = self.app_model.get_state();
+        let page = &state.browser.show_details_state(&id)?.next_page;
+        let next_offset = page.next_offset? as u32;
+        let batch_size = page.batch_size as u32;
+
+        self.dispatcher.dispatch_async(Box::pin(async move {
+            match api.get_show_episodes(&id, next_offset, batch_size).await {
+                Ok(episodes) => Some(BrowserAction::AppendShowEpisodes(id, episodes).into()),
+                Err(err) => handle_error(err),
+            }
+        }));
+
+        Some(())
+    }
+}
+
+impl ShowDetailsModel {
+    fn state(&self) -> Ref<'_, AppState> {
+        self.app_model.get_state()
+    }
+}
+
+impl PlaylistModel for ShowDetailsModel {
+    fn current_song_id(&self) -> Option<String> {
+        self.state().playback.current_song_id().cloned()
+    }
+
+    fn play_song(&self, id: &str) {
+        let source = Some(PlaylistSource::Show(self.id.clone()));
+        if self.app_model.get_state().playback.source() != source.as_ref() {
+            let episodes = self.episodes_ref();
+            if let Some(episodes) = episodes {
+                let songs = episodes.iter().map(|e| e.to_song_description()).collect();
+                self.dispatcher
+                    .dispatch(PlaybackAction::LoadPlaylist(source, songs).into());
+            }
+        }
+        self.dispatcher
+            .dispatch(PlaybackAction::Load(id.to_string()).into());
+    }
+
+    fn diff_for_event(&self, event: &AppEvent) -> Option<ListDiff<SongModel>> {
+        match event {
+            AppEvent::BrowserEvent(BrowserEvent::ShowDetailsLoaded(id)) if id == &self.id => {
+                let episodes = self.episodes_ref()?;
+                Some(ListDiff::Set(
+                    episodes
+                        .iter()
+                        .enumerate()
+                        .map(|(i, e)| e.to_song_model(i))
+                        .collect(),
+                ))
+            }
+            AppEvent::BrowserEvent(BrowserEvent::ShowEpisodesAppended(id, index))
+                if id == &self.id =>
+            {
+                let episodes = self.episodes_ref()?;
+                Some(ListDiff::Append(
+                    episodes
+                        .iter()
+                        .enumerate()
+                        .skip(*index)
+                        .map(|(i, e)| e.to_song_model(i))
+                        .collect(),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    fn actions_for(&self, id: &str) -> Option<gio::ActionGroup> {
+        let episodes = self.episodes_ref()?;
+        let episode = episodes.iter().find(|&episode| episode.id == id)?;
+
+        let group = SimpleActionGroup::new();
+
+        group.add_action(&episode.make_link_action(None));
+        group.add_action(&episode.make_queue_action(self.dispatcher.box_clone(), None));
+        group.add_action(&self.make_go_to_show_action());
+
+        Some(group.upcast())
+    }
+
+    fn menu_for(&self, id: &str) -> Option<gio::MenuModel> {
+        let episodes = self.episodes_ref()?;
+        let _episode = episodes.iter().find(|&episode| episode.id == id)?;
+
+        let menu = gio::Menu::new();
+        menu.append(Some(&*labels::COPY_LINK), Some("song.copy_link"));
+        menu.append(Some(&*labels::ADD_TO_QUEUE), Some("song.queue"));
+        menu.append(Some(&*labels::GO_TO_SHOW), Some("song.go_to_show"));
+
+        Some(menu.upcast())
+    }
+
+    fn select_song(&self, id: &str) {
+        let episode = self
+            .episodes_ref()
+            .and_then(|episodes| episodes.iter().find(|&episode| episode.id == id).cloned());
+        if let Some(episode) = episode {
+            self.dispatcher.dispatch(
+                SelectionAction::Select(vec![episode.to_song_description()]).into(),
+            );
+        }
+    }
+
+    fn deselect_song(&self, id: &str) {
+        self.dispatcher
+            .dispatch(SelectionAction::Deselect(vec![id.to_string()]).into());
+    }
+
+    fn enable_selection(&self) -> bool {
+        self.dispatcher
+            .dispatch(AppAction::ChangeSelectionMode(true));
+        true
+    }
+
+    fn selection(&self) -> Option<Box<dyn Deref<Target = SelectionState> + '_>> {
+        Some(Box::new(self.app_model.map_state(|s| &s.selection)))
+    }
+}
+
+impl ShowDetailsModel {
+    fn make_go_to_show_action(&self) -> gio::SimpleAction {
+        let action = gio::SimpleAction::new("go_to_show", None);
+        let dispatcher = self.dispatcher.box_clone();
+        let id = self.id.clone();
+
+        action.connect_activate(move |_, _| {
+            dispatcher.dispatch(AppAction::ViewShow(id.clone()));
+        });
+
+        action
+    }
+}
+
+impl SelectionToolsModel for ShowDetailsModel {
+    fn selection(&self) -> Option<Box<dyn Deref<Target = SelectionState> + '_>> {
+        Some(Box::new(self.app_model.map_state(|s| &s.selection)))
+    }
+
+    fn handle_tool_activated(&self, selection: &SelectionState, tool: &SelectionTool) {
+        let action = match (tool, tool.default_action()) {
+            (_, Some(action)) => Some(action),
+            (SelectionTool::SelectAll, None) => self.episodes_ref().map(|episodes| {
+                let episodes = &*episodes;
+                let all_selected = selection.all_selected(episodes.iter().map(|e| &e.id));
+
+                if all_selected {
+                    SelectionAction::Deselect(episodes.iter().map(|e| &e.id).cloned().collect())
+                } else {
+                    SelectionAction::Select(
+                        episodes.iter().map(|e| e.to_song_description()).collect(),
+                    )
+                }
+                .into()
+            }),
+            _ => None,
+        };
+        if let Some(action) = action {
+            self.dispatcher.dispatch(action);
+        }
+    }
+}
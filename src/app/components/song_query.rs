@@ -0,0 +1,194 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::app::models::SongDescription;
+
+pub fn query_tokens(query: &str) -> Vec<String> {
+    query
+        .split_whitespace()
+        .map(|token| token.to_lowercase())
+        .collect()
+}
+
+pub fn matches_query(song: &SongDescription, tokens: &[String]) -> bool {
+    SongMatcher::new(tokens).matches(song)
+}
+
+/// A query matcher built once per search and reused across every song in
+/// the list, so filtering a whole playlist only builds one automaton
+/// instead of rebuilding it per song.
+pub struct SongMatcher {
+    matcher: PatternMatcher,
+}
+
+impl SongMatcher {
+    pub fn new(tokens: &[String]) -> Self {
+        Self {
+            matcher: PatternMatcher::new(tokens),
+        }
+    }
+
+    pub fn matches(&self, song: &SongDescription) -> bool {
+        self.matcher.matches_all(&song_haystack(song))
+    }
+}
+
+fn song_haystack(song: &SongDescription) -> String {
+    let artists = song
+        .artists
+        .iter()
+        .map(|a| a.name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("{} — {}", song.title, artists).to_lowercase()
+}
+
+/// A minimal Aho-Corasick automaton: finds, in a single left-to-right scan of
+/// the haystack, whether every one of a set of patterns occurs at least once.
+struct Node {
+    children: HashMap<char, usize>,
+    fail: usize,
+    output: Vec<usize>,
+}
+
+struct PatternMatcher {
+    nodes: Vec<Node>,
+    pattern_count: usize,
+}
+
+impl PatternMatcher {
+    fn new(patterns: &[String]) -> Self {
+        let mut nodes = vec![Node {
+            children: HashMap::new(),
+            fail: 0,
+            output: Vec::new(),
+        }];
+
+        for (pattern_idx, pattern) in patterns.iter().enumerate() {
+            let mut state = 0;
+            for ch in pattern.chars() {
+                state = *nodes[state].children.entry(ch).or_insert_with(|| {
+                    nodes.push(Node {
+                        children: HashMap::new(),
+                        fail: 0,
+                        output: Vec::new(),
+                    });
+                    nodes.len() - 1
+                });
+            }
+            nodes[state].output.push(pattern_idx);
+        }
+
+        let mut queue = VecDeque::new();
+        let root_children: Vec<usize> = nodes[0].children.values().copied().collect();
+        for child in root_children {
+            nodes[child].fail = 0;
+            queue.push_back(child);
+        }
+
+        while let Some(state) = queue.pop_front() {
+            let transitions: Vec<(char, usize)> = nodes[state]
+                .children
+                .iter()
+                .map(|(&ch, &next)| (ch, next))
+                .collect();
+
+            for (ch, next) in transitions {
+                let mut fail = nodes[state].fail;
+                while fail != 0 && !nodes[fail].children.contains_key(&ch) {
+                    fail = nodes[fail].fail;
+                }
+                let candidate = nodes[fail].children.get(&ch).copied().unwrap_or(0);
+                nodes[next].fail = if candidate == next { 0 } else { candidate };
+
+                let inherited = nodes[nodes[next].fail].output.clone();
+                nodes[next].output.extend(inherited);
+
+                queue.push_back(next);
+            }
+        }
+
+        Self {
+            nodes,
+            pattern_count: patterns.len(),
+        }
+    }
+
+    fn matches_all(&self, haystack: &str) -> bool {
+        if self.pattern_count == 0 {
+            return true;
+        }
+
+        let mut found = vec![false; self.pattern_count];
+        let mut remaining = self.pattern_count;
+        let mut state = 0;
+
+        for ch in haystack.chars() {
+            while state != 0 && !self.nodes[state].children.contains_key(&ch) {
+                state = self.nodes[state].fail;
+            }
+            state = self.nodes[state].children.get(&ch).copied().unwrap_or(0);
+
+            for &pattern_idx in &self.nodes[state].output {
+                if !found[pattern_idx] {
+                    found[pattern_idx] = true;
+                    remaining -= 1;
+                }
+            }
+
+            if remaining == 0 {
+                return true;
+            }
+        }
+
+        remaining == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::models::ArtistRef;
+
+    fn song(title: &str, artist: &str) -> SongDescription {
+        SongDescription {
+            id: "id".to_string(),
+            title: title.to_string(),
+            artists: vec![ArtistRef {
+                id: "artist-id".to_string(),
+                name: artist.to_string(),
+            }],
+            duration: 0,
+            added_at: None,
+        }
+    }
+
+    #[test]
+    fn empty_query_matches_everything() {
+        assert!(matches_query(&song("Title", "Artist"), &query_tokens("")));
+    }
+
+    #[test]
+    fn matches_when_every_token_present_in_title_or_artist() {
+        let s = song("Blinding Lights", "The Weeknd");
+        assert!(matches_query(&s, &query_tokens("blinding weeknd")));
+    }
+
+    #[test]
+    fn does_not_match_when_a_token_is_missing() {
+        let s = song("Blinding Lights", "The Weeknd");
+        assert!(!matches_query(&s, &query_tokens("blinding drake")));
+    }
+
+    #[test]
+    fn matching_is_case_insensitive() {
+        let s = song("Blinding Lights", "The Weeknd");
+        assert!(matches_query(&s, &query_tokens("BLINDING")));
+    }
+
+    #[test]
+    fn matcher_finds_all_patterns_in_a_single_scan() {
+        let matcher = PatternMatcher::new(&["ab".to_string(), "cd".to_string()]);
+        assert!(matcher.matches_all("xxabxxcdxx"));
+        assert!(!matcher.matches_all("xxabxxxxxx"));
+    }
+}
@@ -0,0 +1,12 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use crate::app::AppAction;
+
+pub type DispatchFuture = Pin<Box<dyn Future<Output = Option<AppAction>>>>;
+
+pub trait ActionDispatcher {
+    fn dispatch(&self, action: AppAction);
+    fn dispatch_async(&self, future: DispatchFuture);
+    fn box_clone(&self) -> Box<dyn ActionDispatcher>;
+}
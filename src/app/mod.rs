@@ -0,0 +1,63 @@
+pub mod backend;
+pub mod components;
+pub mod dispatch;
+pub mod models;
+pub mod state;
+
+use std::cell::{Ref, RefCell};
+use std::ops::Deref;
+use std::rc::Rc;
+
+pub use backend::SpotifyApiClient;
+pub use dispatch::ActionDispatcher;
+pub use state::{AppAction, AppEvent, AppState};
+
+pub enum ListDiff<T> {
+    Set(Vec<T>),
+    Append(Vec<T>),
+}
+
+pub struct AppModel {
+    state: RefCell<AppState>,
+    spotify: Rc<dyn SpotifyApiClient>,
+}
+
+impl AppModel {
+    pub fn new(spotify: Rc<dyn SpotifyApiClient>) -> Self {
+        Self {
+            state: RefCell::new(AppState::default()),
+            spotify,
+        }
+    }
+
+    pub fn get_state(&self) -> Ref<'_, AppState> {
+        self.state.borrow()
+    }
+
+    pub fn update_state(&self, action: AppAction) -> Vec<AppEvent> {
+        self.state.borrow_mut().update_state(action)
+    }
+
+    pub fn get_spotify(&self) -> Rc<dyn SpotifyApiClient> {
+        Rc::clone(&self.spotify)
+    }
+
+    pub fn map_state<F, T: ?Sized>(&self, f: F) -> impl Deref<Target = T> + '_
+    where
+        F: Fn(&AppState) -> &T,
+    {
+        Ref::map(self.state.borrow(), f)
+    }
+
+    pub fn map_state_opt<F, T: ?Sized>(&self, f: F) -> Option<impl Deref<Target = T> + '_>
+    where
+        F: Fn(&AppState) -> Option<&T>,
+    {
+        let found = f(&self.state.borrow()).is_some();
+        if found {
+            Some(Ref::map(self.state.borrow(), |s| f(s).unwrap()))
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use crate::app::dispatch::ActionDispatcher;
+use crate::app::state::AppAction;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArtistRef {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UserRef {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct SongDescription {
+    pub id: String,
+    pub title: String,
+    pub artists: Vec<ArtistRef>,
+    pub duration: i64,
+    pub added_at: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct PlaylistDescription {
+    pub id: String,
+    pub title: String,
+    pub owner: UserRef,
+    pub songs: Vec<SongDescription>,
+}
+
+#[derive(Clone, Debug)]
+pub struct AlbumDescription {
+    pub id: String,
+    pub title: String,
+    pub artists: Vec<ArtistRef>,
+    pub is_liked: bool,
+    pub songs: Vec<SongDescription>,
+}
+
+#[derive(Clone, Debug)]
+pub struct EpisodeDescription {
+    pub id: String,
+    pub title: String,
+    pub show_id: String,
+    pub show_name: String,
+    pub duration: i64,
+    pub release_date: String,
+    pub resume_position: Option<i64>,
+    pub description: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct ShowDescription {
+    pub id: String,
+    pub title: String,
+    pub publisher: String,
+    pub episodes: Vec<EpisodeDescription>,
+}
+
+#[derive(Clone, Debug)]
+pub struct SongModel {
+    pub index: usize,
+    pub id: String,
+    pub title: String,
+    pub artist: String,
+}
+
+impl SongModel {
+    pub fn new(index: usize, id: String, title: String, artist: String) -> Self {
+        Self {
+            index,
+            id,
+            title,
+            artist,
+        }
+    }
+}
+
+fn action_name(base: &str, group: Option<&str>) -> String {
+    match group {
+        Some(group) => format!("{}_{}", base, group),
+        None => base.to_string(),
+    }
+}
+
+impl SongDescription {
+    pub fn to_song_model(&self, index: usize) -> SongModel {
+        let artist = self
+            .artists
+            .first()
+            .map(|a| a.name.clone())
+            .unwrap_or_default();
+        SongModel::new(index, self.id.clone(), self.title.clone(), artist)
+    }
+
+    pub fn make_artist_actions(
+        &self,
+        dispatcher: Box<dyn ActionDispatcher>,
+        group: Option<&str>,
+    ) -> Vec<gio::SimpleAction> {
+        self.artists
+            .iter()
+            .map(|artist| {
+                let action = gio::SimpleAction::new(
+                    &action_name(&format!("view_artist_{}", artist.id), group),
+                    None,
+                );
+                let dispatcher = dispatcher.box_clone();
+                let id = artist.id.clone();
+                action.connect_activate(move |_, _| {
+                    dispatcher.dispatch(AppAction::ViewArtist(id.clone()));
+                });
+                action
+            })
+            .collect()
+    }
+
+    pub fn make_album_action(
+        &self,
+        _dispatcher: Box<dyn ActionDispatcher>,
+        group: Option<&str>,
+    ) -> gio::SimpleAction {
+        gio::SimpleAction::new(&action_name("view_album", group), None)
+    }
+
+    pub fn make_link_action(&self, group: Option<&str>) -> gio::SimpleAction {
+        gio::SimpleAction::new(&action_name("copy_link", group), None)
+    }
+
+    pub fn make_queue_action(
+        &self,
+        _dispatcher: Box<dyn ActionDispatcher>,
+        group: Option<&str>,
+    ) -> gio::SimpleAction {
+        gio::SimpleAction::new(&action_name("queue", group), None)
+    }
+}
+
+impl EpisodeDescription {
+    pub fn to_song_model(&self, index: usize) -> SongModel {
+        SongModel::new(
+            index,
+            self.id.clone(),
+            self.title.clone(),
+            self.show_name.clone(),
+        )
+    }
+
+    pub fn to_song_description(&self) -> SongDescription {
+        SongDescription {
+            id: self.id.clone(),
+            title: self.title.clone(),
+            artists: vec![ArtistRef {
+                id: self.show_id.clone(),
+                name: self.show_name.clone(),
+            }],
+            duration: self.duration,
+            added_at: None,
+        }
+    }
+
+    pub fn make_link_action(&self, group: Option<&str>) -> gio::SimpleAction {
+        gio::SimpleAction::new(&action_name("copy_link", group), None)
+    }
+
+    pub fn make_queue_action(
+        &self,
+        _dispatcher: Box<dyn ActionDispatcher>,
+        group: Option<&str>,
+    ) -> gio::SimpleAction {
+        gio::SimpleAction::new(&action_name("queue", group), None)
+    }
+}
@@ -0,0 +1,76 @@
+use crate::app::models::{
+    AlbumDescription, EpisodeDescription, PlaylistDescription, ShowDescription, SongDescription,
+};
+
+#[derive(Clone, Debug)]
+pub enum AppAction {
+    ViewUser(String),
+    ViewArtist(String),
+    ViewShow(String),
+    ChangeSelectionMode(bool),
+    BrowserAction(BrowserAction),
+    PlaybackAction(PlaybackAction),
+    SelectionAction(SelectionAction),
+    FilterAction(FilterAction),
+}
+
+#[derive(Clone, Debug)]
+pub enum BrowserAction {
+    SetPlaylistDetails(PlaylistDescription),
+    AppendPlaylistTracks(String, Vec<SongDescription>),
+    SetAlbumDetails(AlbumDescription),
+    SaveAlbum(AlbumDescription),
+    UnsaveAlbum(String),
+    ReorderSongs(String, Vec<String>),
+    SetShowDetails(ShowDescription),
+    AppendShowEpisodes(String, Vec<EpisodeDescription>),
+}
+
+#[derive(Clone, Debug)]
+pub enum FilterAction {
+    UpdateQuery(String, String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PlaylistSource {
+    Playlist(String),
+    Album(String),
+    Radio(String),
+    Show(String),
+}
+
+#[derive(Clone, Debug)]
+pub enum PlaybackAction {
+    LoadPlaylist(Option<PlaylistSource>, Vec<SongDescription>),
+    Load(String),
+}
+
+#[derive(Clone, Debug)]
+pub enum SelectionAction {
+    Select(Vec<SongDescription>),
+    Deselect(Vec<String>),
+}
+
+impl From<BrowserAction> for AppAction {
+    fn from(action: BrowserAction) -> Self {
+        AppAction::BrowserAction(action)
+    }
+}
+
+impl From<PlaybackAction> for AppAction {
+    fn from(action: PlaybackAction) -> Self {
+        AppAction::PlaybackAction(action)
+    }
+}
+
+impl From<SelectionAction> for AppAction {
+    fn from(action: SelectionAction) -> Self {
+        AppAction::SelectionAction(action)
+    }
+}
+
+impl From<FilterAction> for AppAction {
+    fn from(action: FilterAction) -> Self {
+        AppAction::FilterAction(action)
+    }
+}
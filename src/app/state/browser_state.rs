@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+
+use crate::app::models::{AlbumDescription, PlaylistDescription, ShowDescription, SongDescription};
+use crate::app::state::{BrowserAction, BrowserEvent, FilterAction};
+
+fn reorder_songs(songs: &mut Vec<SongDescription>, ordered_ids: &[String]) {
+    let mut by_id: HashMap<String, SongDescription> =
+        songs.drain(..).map(|s| (s.id.clone(), s)).collect();
+    songs.extend(ordered_ids.iter().filter_map(|id| by_id.remove(id)));
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Pagination {
+    pub next_offset: Option<usize>,
+    pub batch_size: usize,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct PlaylistDetailsState {
+    pub playlist: Option<PlaylistDescription>,
+    pub next_page: Pagination,
+    pub filter: String,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct DetailsState {
+    pub content: Option<AlbumDescription>,
+    pub filter: String,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ShowDetailsState {
+    pub show: Option<ShowDescription>,
+    pub next_page: Pagination,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct BrowserState {
+    pub playlist_details: HashMap<String, PlaylistDetailsState>,
+    pub details: HashMap<String, DetailsState>,
+    pub show_details: HashMap<String, ShowDetailsState>,
+}
+
+impl BrowserState {
+    pub fn playlist_details_state(&self, id: &str) -> Option<&PlaylistDetailsState> {
+        self.playlist_details.get(id)
+    }
+
+    pub fn details_state(&self, id: &str) -> Option<&DetailsState> {
+        self.details.get(id)
+    }
+
+    pub fn show_details_state(&self, id: &str) -> Option<&ShowDetailsState> {
+        self.show_details.get(id)
+    }
+
+    pub fn update_state(&mut self, action: BrowserAction) -> Vec<BrowserEvent> {
+        match action {
+            BrowserAction::SetPlaylistDetails(playlist) => {
+                let id = playlist.id.clone();
+                let entry = self.playlist_details.entry(id.clone()).or_default();
+                entry.playlist = Some(playlist);
+                vec![BrowserEvent::PlaylistDetailsLoaded(id)]
+            }
+            BrowserAction::AppendPlaylistTracks(id, tracks) => {
+                if let Some(state) = self.playlist_details.get_mut(&id) {
+                    if let Some(playlist) = state.playlist.as_mut() {
+                        let index = playlist.songs.len();
+                        playlist.songs.extend(tracks);
+                        return vec![BrowserEvent::PlaylistTracksAppended(id, index)];
+                    }
+                }
+                vec![]
+            }
+            BrowserAction::SetAlbumDetails(album) => {
+                let id = album.id.clone();
+                let entry = self.details.entry(id.clone()).or_default();
+                entry.content = Some(album);
+                vec![BrowserEvent::AlbumDetailsLoaded(id)]
+            }
+            BrowserAction::SaveAlbum(album) => {
+                let id = album.id.clone();
+                if let Some(state) = self.details.get_mut(&id) {
+                    if let Some(content) = state.content.as_mut() {
+                        content.is_liked = true;
+                    }
+                }
+                vec![BrowserEvent::AlbumDetailsLoaded(id)]
+            }
+            BrowserAction::UnsaveAlbum(id) => {
+                if let Some(state) = self.details.get_mut(&id) {
+                    if let Some(content) = state.content.as_mut() {
+                        content.is_liked = false;
+                    }
+                }
+                vec![BrowserEvent::AlbumDetailsLoaded(id)]
+            }
+            BrowserAction::ReorderSongs(id, ordered_ids) => {
+                if let Some(state) = self.playlist_details.get_mut(&id) {
+                    if let Some(playlist) = state.playlist.as_mut() {
+                        reorder_songs(&mut playlist.songs, &ordered_ids);
+                        return vec![BrowserEvent::SongsReordered(id)];
+                    }
+                }
+                if let Some(state) = self.details.get_mut(&id) {
+                    if let Some(content) = state.content.as_mut() {
+                        reorder_songs(&mut content.songs, &ordered_ids);
+                        return vec![BrowserEvent::SongsReordered(id)];
+                    }
+                }
+                vec![]
+            }
+            BrowserAction::SetShowDetails(show) => {
+                let id = show.id.clone();
+                let entry = self.show_details.entry(id.clone()).or_default();
+                entry.show = Some(show);
+                vec![BrowserEvent::ShowDetailsLoaded(id)]
+            }
+            BrowserAction::AppendShowEpisodes(id, episodes) => {
+                if let Some(state) = self.show_details.get_mut(&id) {
+                    if let Some(show) = state.show.as_mut() {
+                        let index = show.episodes.len();
+                        show.episodes.extend(episodes);
+                        return vec![BrowserEvent::ShowEpisodesAppended(id, index)];
+                    }
+                }
+                vec![]
+            }
+        }
+    }
+
+    pub fn update_filter(&mut self, action: FilterAction) -> Vec<BrowserEvent> {
+        match action {
+            FilterAction::UpdateQuery(id, query) => {
+                if let Some(state) = self.playlist_details.get_mut(&id) {
+                    state.filter = query.clone();
+                }
+                if let Some(state) = self.details.get_mut(&id) {
+                    state.filter = query;
+                }
+                vec![BrowserEvent::FilterChanged(id)]
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::models::ArtistRef;
+
+    fn song(id: &str) -> SongDescription {
+        SongDescription {
+            id: id.to_string(),
+            title: id.to_string(),
+            artists: vec![ArtistRef {
+                id: "artist".to_string(),
+                name: "Artist".to_string(),
+            }],
+            duration: 0,
+            added_at: None,
+        }
+    }
+
+    #[test]
+    fn reorder_songs_applies_the_given_id_order() {
+        let mut songs = vec![song("a"), song("b"), song("c")];
+        reorder_songs(&mut songs, &["c".to_string(), "a".to_string(), "b".to_string()]);
+        let ids: Vec<&str> = songs.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["c", "a", "b"]);
+    }
+
+    #[test]
+    fn reorder_songs_drops_ids_that_no_longer_exist() {
+        let mut songs = vec![song("a"), song("b")];
+        reorder_songs(&mut songs, &["b".to_string(), "missing".to_string()]);
+        let ids: Vec<&str> = songs.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["b"]);
+    }
+}
@@ -0,0 +1,15 @@
+#[derive(Clone, Debug)]
+pub enum AppEvent {
+    BrowserEvent(BrowserEvent),
+}
+
+#[derive(Clone, Debug)]
+pub enum BrowserEvent {
+    PlaylistDetailsLoaded(String),
+    PlaylistTracksAppended(String, usize),
+    AlbumDetailsLoaded(String),
+    FilterChanged(String),
+    SongsReordered(String),
+    ShowDetailsLoaded(String),
+    ShowEpisodesAppended(String, usize),
+}
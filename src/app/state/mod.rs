@@ -0,0 +1,51 @@
+mod actions;
+mod browser_state;
+mod events;
+mod playback_state;
+mod selection_state;
+
+pub use actions::*;
+pub use browser_state::*;
+pub use events::*;
+pub use playback_state::*;
+pub use selection_state::*;
+
+#[derive(Clone, Debug, Default)]
+pub struct AppState {
+    pub browser: BrowserState,
+    pub playback: PlaybackState,
+    pub selection: SelectionState,
+}
+
+impl AppState {
+    pub fn update_state(&mut self, action: AppAction) -> Vec<AppEvent> {
+        match action {
+            AppAction::BrowserAction(action) => self
+                .browser
+                .update_state(action)
+                .into_iter()
+                .map(AppEvent::BrowserEvent)
+                .collect(),
+            AppAction::FilterAction(action) => self
+                .browser
+                .update_filter(action)
+                .into_iter()
+                .map(AppEvent::BrowserEvent)
+                .collect(),
+            AppAction::PlaybackAction(action) => {
+                self.playback.update_state(action);
+                vec![]
+            }
+            AppAction::SelectionAction(action) => {
+                self.selection.update_state(action);
+                vec![]
+            }
+            AppAction::ViewUser(_)
+            | AppAction::ViewArtist(_)
+            | AppAction::ViewShow(_)
+            | AppAction::ChangeSelectionMode(_) => {
+                vec![]
+            }
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::app::models::SongDescription;
+use crate::app::state::{PlaybackAction, PlaylistSource};
+
+#[derive(Clone, Debug, Default)]
+pub struct PlaybackState {
+    source: Option<PlaylistSource>,
+    songs: Vec<SongDescription>,
+    current: Option<String>,
+}
+
+impl PlaybackState {
+    pub fn source(&self) -> Option<&PlaylistSource> {
+        self.source.as_ref()
+    }
+
+    pub fn current_song_id(&self) -> Option<&String> {
+        self.current.as_ref()
+    }
+
+    pub fn songs(&self) -> &[SongDescription] {
+        &self.songs
+    }
+
+    pub fn update_state(&mut self, action: PlaybackAction) {
+        match action {
+            PlaybackAction::LoadPlaylist(source, songs) => {
+                self.source = source;
+                self.songs = songs;
+            }
+            PlaybackAction::Load(id) => {
+                self.current = Some(id);
+            }
+        }
+    }
+}
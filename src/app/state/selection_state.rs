@@ -0,0 +1,44 @@
+use crate::app::models::SongDescription;
+use crate::app::state::{AppAction, SelectionAction};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SelectionTool {
+    SelectAll,
+    SortByTitle,
+    SortByArtist,
+    SortByDuration,
+    SortByAddedDate,
+}
+
+impl SelectionTool {
+    pub fn default_action(&self) -> Option<AppAction> {
+        None
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SelectionState {
+    selected_songs: Vec<SongDescription>,
+}
+
+impl SelectionState {
+    pub fn all_selected<'a>(&self, ids: impl Iterator<Item = &'a String>) -> bool {
+        ids.into_iter()
+            .all(|id| self.selected_songs.iter().any(|s| &s.id == id))
+    }
+
+    pub fn update_state(&mut self, action: SelectionAction) {
+        match action {
+            SelectionAction::Select(songs) => {
+                for song in songs {
+                    if !self.selected_songs.iter().any(|s| s.id == song.id) {
+                        self.selected_songs.push(song);
+                    }
+                }
+            }
+            SelectionAction::Deselect(ids) => {
+                self.selected_songs.retain(|s| !ids.contains(&s.id));
+            }
+        }
+    }
+}